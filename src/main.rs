@@ -1,13 +1,22 @@
 use std::env;
 use std::fs::File;
 use std::io::{self, Write};
-use std::collections::{HashSet};
+use std::net::ToSocketAddrs;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
 use reqwest;
 use std::error::Error;
 use env_logger::{Builder, Target};
 use scraper::{Html, Selector};
+use tokio::sync::{mpsc, Notify, Semaphore};
 use url::Url;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+
+/// Number of processed URLs between automatic checkpoints when `--state` is set.
+const CHECKPOINT_INTERVAL: usize = 50;
 
 enum OutputFormat {
     PlainText(File),
@@ -17,15 +26,367 @@ enum OutputFormat {
 struct Config {
     root_url: String,
     depth: i32,
+    jobs: usize,
     verbose: bool,
     response_error: bool,
+    ignore_robots: bool,
+    rate: Option<f64>,
+    burst: Option<f64>,
+    assets: bool,
+    check_tls: Option<i64>,
+    state_file: Option<String>,
     output_file: Option<OutputFormat>,
 }
 
+/// A serializable snapshot of the crawl: everything needed to resume an
+/// interrupted run from where it left off instead of restarting at the root.
+#[derive(Serialize, Deserialize, Default)]
+struct CrawlState {
+    root: String,
+    depth: i32,
+    visited: HashSet<String>,
+    to_visit: Vec<String>,
+    error_links: Vec<String>,
+}
+
+impl CrawlState {
+    /// Writes the snapshot to `path` as pretty JSON, logging but not failing on
+    /// I/O errors so a checkpoint problem never aborts an otherwise healthy crawl.
+    fn save(&self, path: &str) {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(path, json) {
+                    log::error!("Failed to write state file {}: {}", path, e);
+                }
+            }
+            Err(e) => log::error!("Failed to serialize crawl state: {}", e),
+        }
+    }
+
+    /// Loads a previously saved snapshot, returning `None` when the file is
+    /// absent or unreadable so the caller can start a fresh crawl.
+    fn load(path: &str) -> Option<CrawlState> {
+        let data = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&data).ok()
+    }
+}
+
+/// A per-host token bucket: it holds up to `burst` tokens, refilled at `rate`
+/// tokens per second, and optionally enforces a minimum spacing between
+/// requests (from a robots.txt `Crawl-delay`).
+struct Bucket {
+    tokens: f64,
+    last: Instant,
+    rate: Option<f64>,
+    burst: f64,
+    min_interval: Option<Duration>,
+    next_allowed: Instant,
+}
+
+impl Bucket {
+    /// Reserves one request slot and returns how long the caller must sleep
+    /// before it may fire. The bucket state is advanced as if the wait has
+    /// already elapsed, so concurrent workers queue behind each other.
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let mut wait = Duration::ZERO;
+
+        if let Some(rate) = self.rate {
+            // Advance from a forward cursor so concurrent reservations stack:
+            // a prior reservation may have pushed `self.last` past `now`, and
+            // refill/wait must be measured from that later point.
+            let base = self.last.max(now);
+            let elapsed = base.saturating_duration_since(self.last).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * rate).min(self.burst);
+            if self.tokens >= 1.0 {
+                self.tokens -= 1.0;
+                self.last = base;
+            } else {
+                let needed = (1.0 - self.tokens) / rate;
+                let ready = base + Duration::from_secs_f64(needed);
+                wait = ready.saturating_duration_since(now);
+                self.tokens = 0.0;
+                self.last = ready;
+            }
+        }
+
+        if let Some(interval) = self.min_interval {
+            let earliest = self.next_allowed.max(now + wait);
+            wait = earliest.saturating_duration_since(now);
+            self.next_allowed = earliest + interval;
+        }
+
+        wait
+    }
+}
+
+/// Shares token buckets across all workers so the configured rate/burst and any
+/// robots.txt `Crawl-delay` are enforced per host regardless of concurrency.
+struct RateLimiter {
+    rate: Option<f64>,
+    burst: f64,
+    crawl_delay: Option<f64>,
+    buckets: HashMap<String, Bucket>,
+}
+
+impl RateLimiter {
+    /// Reserves a request slot for `host`, returning the required sleep.
+    /// With no rate limit and no crawl-delay this is always zero-cost.
+    fn reserve(&mut self, host: &str, now: Instant) -> Duration {
+        if self.rate.is_none() && self.crawl_delay.is_none() {
+            return Duration::ZERO;
+        }
+
+        let rate = self.rate;
+        let burst = self.burst;
+        let min_interval = self.crawl_delay.map(Duration::from_secs_f64);
+        let bucket = self.buckets.entry(host.to_string()).or_insert_with(|| Bucket {
+            tokens: burst,
+            last: now,
+            rate,
+            burst,
+            min_interval,
+            next_allowed: now,
+        });
+
+        bucket.reserve(now)
+    }
+}
+
+/// Crawl rules harvested from a host's `robots.txt` for the `*` user-agent.
+/// Paths are matched as prefixes; the longest matching `Allow`/`Disallow`
+/// rule wins, following the de-facto robots precedence.
+#[derive(Clone, Default)]
+struct RobotsRules {
+    disallow: Vec<String>,
+    allow: Vec<String>,
+    crawl_delay: Option<f64>,
+}
+
+impl RobotsRules {
+    /// Returns whether `path` may be fetched under these rules.
+    /// e.g., with `Disallow: /private`, is_allowed("/private/x") -> false
+    fn is_allowed(&self, path: &str) -> bool {
+        let longest = |rules: &[String]| {
+            rules
+                .iter()
+                .filter(|p| path.starts_with(p.as_str()))
+                .map(|p| p.len())
+                .max()
+        };
+
+        match (longest(&self.allow), longest(&self.disallow)) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(allow), Some(disallow)) => allow >= disallow,
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct CrawlOutput<'a> {
     root: &'a str,
     found_urls: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assets: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tls: Vec<&'a TlsInfo>,
+}
+
+/// The TLS certificate validity window recorded for one HTTPS host.
+#[derive(Clone, Serialize)]
+struct TlsInfo {
+    host: String,
+    expires: String,
+    days_remaining: i64,
+    expired: bool,
+    expiring_soon: bool,
+}
+
+/// Distinguishes navigable HTML links (anchors, frames, forms) from static
+/// asset references (stylesheets, scripts, images) found on a page.
+#[derive(Clone, Copy, PartialEq)]
+enum LinkKind {
+    Page,
+    Asset,
+}
+
+/// Shared crawl context handed to every worker task.
+/// All fields are cheap to clone (plain copies, `Arc`s, a channel sender and
+/// the optional state path), so each spawned
+/// worker owns its own handle to the same frontier and visited/error sets.
+#[derive(Clone)]
+struct Crawler {
+    root: Arc<String>,
+    depth: i32,
+    verbose: bool,
+    response_error: bool,
+    assets: bool,
+    check_tls: Option<i64>,
+    robots: Arc<RobotsRules>,
+    limiter: Arc<Mutex<RateLimiter>>,
+    tls: Arc<Mutex<HashMap<String, Option<TlsInfo>>>>,
+    state_file: Option<String>,
+    visited: Arc<Mutex<HashSet<String>>>,
+    asset_urls: Arc<Mutex<HashSet<String>>>,
+    error_links: Arc<Mutex<Vec<String>>>,
+    // URLs queued but not yet fully processed — the resumable frontier.
+    pending: Arc<Mutex<HashSet<String>>>,
+    processed: Arc<AtomicUsize>,
+    in_flight: Arc<AtomicUsize>,
+    frontier: mpsc::UnboundedSender<String>,
+}
+
+impl Crawler {
+    /// Routes classified links onto the frontier. Asset references are always
+    /// recorded so they can be reported, but only crawled when `--assets` is on.
+    fn ingest(&self, links: Vec<(String, LinkKind)>) {
+        for (url, kind) in links {
+            if kind == LinkKind::Asset {
+                self.asset_urls.lock().unwrap().insert(url.clone());
+                if !self.assets {
+                    continue;
+                }
+            }
+            self.enqueue(url);
+        }
+    }
+
+    /// Pushes a freshly discovered URL onto the frontier, counting it as
+    /// in-flight so the dispatcher only stops once every queued URL is done.
+    fn enqueue(&self, url: String) {
+        self.pending.lock().unwrap().insert(url.clone());
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        // The receiver lives for the whole crawl, so a send only fails during
+        // shutdown; in that case the counter is no longer observed.
+        if self.frontier.send(url.clone()).is_err() {
+            self.pending.lock().unwrap().remove(&url);
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Marks a dequeued URL as done, dropping it from the pending frontier and
+    /// writing a checkpoint every `CHECKPOINT_INTERVAL` URLs when `--state` is set.
+    fn complete(&self, url: &str) {
+        self.pending.lock().unwrap().remove(url);
+        let processed = self.processed.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(path) = &self.state_file {
+            if processed.is_multiple_of(CHECKPOINT_INTERVAL) {
+                self.snapshot().save(path);
+            }
+        }
+    }
+
+    /// Captures the current crawl state for checkpointing or final shutdown.
+    fn snapshot(&self) -> CrawlState {
+        CrawlState {
+            root: self.root.as_ref().clone(),
+            depth: self.depth,
+            visited: self.visited.lock().unwrap().clone(),
+            to_visit: self.pending.lock().unwrap().iter().cloned().collect(),
+            error_links: self.error_links.lock().unwrap().clone(),
+        }
+    }
+
+    /// Probes a host's TLS certificate once, caching the result so repeated
+    /// URLs on the same host don't trigger another handshake.
+    async fn check_host_tls(&self, host: &str, port: u16, threshold: i64) {
+        let key = format!("{}:{}", host, port);
+        {
+            // Claim the host under a single lock: if another worker already
+            // recorded a marker (in-progress or finished), skip the handshake.
+            let mut tls = self.tls.lock().unwrap();
+            if tls.contains_key(&key) {
+                return;
+            }
+            tls.insert(key.clone(), None);
+        }
+
+        let info = probe_tls(host, port, threshold).await;
+        if let Some(info) = &info {
+            if info.expired {
+                log::warn!("TLS certificate for {} expired {} days ago", host, -info.days_remaining);
+            } else if info.expiring_soon {
+                log::warn!("TLS certificate for {} expires in {} days", host, info.days_remaining);
+            }
+        }
+        self.tls.lock().unwrap().insert(key, info);
+    }
+
+    /// Blocks until the shared rate limiter grants a request slot for `host`.
+    /// The lock is released before sleeping so other workers keep making
+    /// progress against their own hosts.
+    async fn acquire(&self, host: &str) {
+        let wait = self.limiter.lock().unwrap().reserve(host, Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Fetches a single URL, records it, and feeds any newly found links back
+    /// onto the frontier. Dedup happens here, under the `visited` lock, so two
+    /// workers never fetch the same URL.
+    async fn process(&self, link: String) {
+        // Skip if from another domain or over the depth limit; still record it
+        // as visited so it shows up in the output like before.
+        if !is_same_domain(&self.root, &link) || depth_control(&link, self.depth) {
+            self.visited.lock().unwrap().insert(link);
+            return;
+        }
+
+        // Honor robots.txt: silently drop disallowed paths. Carry the port so
+        // the TLS audit probes the right endpoint for non-standard ports.
+        let host = match Url::parse(&link) {
+            Ok(parsed) => {
+                if !self.robots.is_allowed(parsed.path()) {
+                    return;
+                }
+                parsed
+                    .host_str()
+                    .map(|h| (h.to_string(), parsed.port_or_known_default().unwrap_or(443)))
+            }
+            Err(_) => None,
+        };
+
+        {
+            let mut visited = self.visited.lock().unwrap();
+            // A failed URL leaves `visited` but stays in `error_links`, so a
+            // copy dispatched before the failure is not fetched again.
+            if self.error_links.lock().unwrap().contains(&link) || !visited.insert(link.clone()) {
+                return; // Already visited by this or another worker
+            }
+        }
+
+        if self.verbose {
+            log::info!("{:?}", &link);
+        }
+
+        // Audit the host's TLS certificate (HTTPS only, once per host).
+        if let (Some(threshold), Some((host, port))) = (self.check_tls, host.as_ref()) {
+            if link.starts_with("https://") {
+                self.check_host_tls(host, *port, threshold).await;
+            }
+        }
+
+        // Rate-limit per host before hitting the network.
+        if let Some((host, _)) = host {
+            self.acquire(&host).await;
+        }
+
+        match get_html(&link).await {
+            Ok(html) => {
+                self.ingest(get_links(&html, &link));
+            }
+            Err(e) => {
+                self.error_links.lock().unwrap().push(link.clone());
+                self.visited.lock().unwrap().remove(&link);
+                log::error!("{:?}", format_reqwest_error(&e));
+                // Print error if user sets arg "-e"
+                if self.response_error {
+                    log::info!("{:?}", &link);
+                }
+            }
+        }
+    }
 }
 
 #[tokio::main]
@@ -34,58 +395,181 @@ async fn main() -> Result<(), reqwest::Error> {
 
     let config = parse_args().expect("Failed to parse arguments");
 
-    let html = get_html(&config.root_url).await?;
-    let mut links = Vec::new();
-    get_links(&html, &config.root_url, &mut links);
-
-    let mut visited: HashSet<String> = HashSet::new();
-    let mut to_visit: Vec<String> = links.clone();
-    let mut error_links: Vec<String> = Vec::new();
-    
-    // Main crawling loop
-    while !to_visit.is_empty() {
-        let current_layer = to_visit.clone();
-        to_visit.clear();
-
-        for link in current_layer {
-            // Skip if the link had a request error
-            if error_links.contains(&link){
-                visited.remove(&link);
-                continue;
-            }
-
-            // Prints any link except already printed
-            if !visited.contains(&link){
-                if config.verbose{
-                        log::info!("{:?}", &link);
+    let visited: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+    let asset_urls: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+    let tls: Arc<Mutex<HashMap<String, Option<TlsInfo>>>> = Arc::new(Mutex::new(HashMap::new()));
+    let error_links: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let pending: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+    let processed = Arc::new(AtomicUsize::new(0));
+    let in_flight = Arc::new(AtomicUsize::new(0));
+
+    // Resume from an earlier checkpoint if one exists for --state.
+    let resumed = config.state_file.as_deref().and_then(CrawlState::load);
+    let is_resume = resumed.is_some();
+    if let Some(state) = &resumed {
+        log::info!("Resuming crawl from {} visited, {} pending", state.visited.len(), state.to_visit.len());
+        // URLs still pending were not finished (some were mid-fetch and already
+        // marked visited), so drop them from `visited` to let them be fetched.
+        let mut restored = state.visited.clone();
+        for link in &state.to_visit {
+            restored.remove(link);
+        }
+        *visited.lock().unwrap() = restored;
+        *error_links.lock().unwrap() = state.error_links.clone();
+    }
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+
+    // Discover robots.txt rules and sitemap sources before crawling begins.
+    let mut sitemaps: Vec<String> = Vec::new();
+    let robots = if config.ignore_robots {
+        RobotsRules::default()
+    } else {
+        let (rules, found) = fetch_robots(&config.root_url).await;
+        sitemaps = found;
+        rules
+    };
+
+    let limiter = RateLimiter {
+        rate: config.rate,
+        // Default the burst to one second's worth of tokens (at least one).
+        burst: config.burst.unwrap_or_else(|| config.rate.unwrap_or(1.0).max(1.0)),
+        crawl_delay: robots.crawl_delay,
+        buckets: HashMap::new(),
+    };
+
+    let crawler = Crawler {
+        root: Arc::new(config.root_url.clone()),
+        depth: config.depth,
+        verbose: config.verbose,
+        response_error: config.response_error,
+        assets: config.assets,
+        check_tls: config.check_tls,
+        robots: Arc::new(robots),
+        limiter: Arc::new(Mutex::new(limiter)),
+        tls: tls.clone(),
+        state_file: config.state_file.clone(),
+        visited: visited.clone(),
+        asset_urls: asset_urls.clone(),
+        error_links: error_links.clone(),
+        pending: pending.clone(),
+        processed: processed.clone(),
+        in_flight: in_flight.clone(),
+        frontier: tx,
+    };
+
+    // The root page is fetched directly below and never passes through
+    // `process`, so probe its TLS certificate here to cover single-page sites
+    // and roots with no same-host child links.
+    if let Some(threshold) = config.check_tls {
+        if let Ok(parsed) = Url::parse(&config.root_url) {
+            if parsed.scheme() == "https" {
+                if let Some(host) = parsed.host_str() {
+                    let port = parsed.port_or_known_default().unwrap_or(443);
+                    crawler.check_host_tls(host, port, threshold).await;
                 }
             }
+        }
+    }
+
+    // Install a Ctrl-C handler that writes a final checkpoint before exiting.
+    if let Some(path) = config.state_file.clone() {
+        let handler = crawler.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                log::info!("Interrupted; writing checkpoint to {}", path);
+                handler.snapshot().save(&path);
+                std::process::exit(0);
+            }
+        });
+    }
 
-            // Skip if already visited, from another domain, or over depth limit
-            if visited.contains(&link) || !is_same_domain(&config.root_url, &link) || depth_control(&link, config.depth) {
-                visited.insert(link);
-                continue;
+    if let Some(state) = resumed {
+        // Continue from the saved frontier instead of re-crawling the root.
+        for link in state.to_visit {
+            crawler.enqueue(link);
+        }
+    } else {
+        // Seed the frontier with the links found on the root page.
+        match get_html(&config.root_url).await {
+            Ok(html) => crawler.ingest(get_links(&html, &config.root_url)),
+            Err(e) => log::error!("{:?}", format_reqwest_error(&e)),
+        }
+    }
+
+    // Seed URLs harvested from sitemaps so pages not reachable via <a href>
+    // still get crawled. Always consult the default /sitemap.xml too.
+    if !is_resume && !config.ignore_robots {
+        if let Ok(default) = Url::parse(&config.root_url).and_then(|u| u.join("/sitemap.xml")) {
+            let default = default.to_string();
+            if !sitemaps.contains(&default) {
+                sitemaps.push(default);
             }
+        }
 
-            // Try to fetch HTML and extract links
-            match get_html(&link).await {
-                Ok(html) => {
-                    visited.insert(link.clone());
-                    get_links(&html, &link, &mut to_visit);
+        let mut seeds = Vec::new();
+        let mut seen = HashSet::new();
+        for sitemap in &sitemaps {
+            collect_sitemap_urls(sitemap, &mut seeds, &mut seen).await;
+        }
+        for url in seeds {
+            crawler.enqueue(url);
+        }
+    }
+
+    // Bounded async worker pool: the dispatcher pulls URLs from the frontier
+    // and spawns a worker per URL, capped by the semaphore. The crawl ends once
+    // the frontier drains and no worker is still in flight.
+    let semaphore = Arc::new(Semaphore::new(config.jobs));
+    let done = Arc::new(Notify::new());
+
+    // Nothing was seeded (failed/empty root, no sitemap URLs): no worker will
+    // ever drive `in_flight` to zero, so skip the dispatcher and exit cleanly.
+    while in_flight.load(Ordering::SeqCst) > 0 {
+        tokio::select! {
+            maybe_link = rx.recv() => {
+                let link = match maybe_link {
+                    Some(link) => link,
+                    None => break,
+                };
+
+                // Skip if the link had a request error
+                if error_links.lock().unwrap().contains(&link) {
+                    crawler.complete(&link);
+                    if in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+                        done.notify_one();
+                    }
+                    continue;
                 }
-                Err(e) => {
-                    error_links.push(link.clone());
-                    visited.remove(&link);
-                    log::error!("{:?}", format_reqwest_error(&e));
-                    // Print error if user sets arg "-e"
-                    if config.response_error{
-                        log::info!("{:?}", &link);
+
+                let permit = semaphore.clone().acquire_owned().await.unwrap();
+                let worker = crawler.clone();
+                let done = done.clone();
+                tokio::spawn(async move {
+                    let finished = link.clone();
+                    worker.process(link).await;
+                    worker.complete(&finished);
+                    drop(permit);
+                    if worker.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+                        done.notify_one();
                     }
-                },
+                });
             }
+            _ = done.notified() => break,
         }
     }
 
+    // Write a final checkpoint now that the frontier is drained.
+    if let Some(path) = &config.state_file {
+        crawler.snapshot().save(path);
+    }
+
+    // Snapshot the shared state for output. Clone rather than unwrap the Arcs:
+    // the Ctrl-C handler task may still hold a reference until the process ends.
+    let mut visited = visited.lock().unwrap().clone();
+    let error_links = error_links.lock().unwrap().clone();
+    let asset_urls = asset_urls.lock().unwrap().clone();
+    let tls_infos: Vec<TlsInfo> = tls.lock().unwrap().values().flatten().cloned().collect();
+
     // Handle output writing based on the selected format
     if let Some(output) = config.output_file {
         match output {
@@ -93,11 +577,36 @@ async fn main() -> Result<(), reqwest::Error> {
                 for link in visited.iter() {
                     writeln!(file, "{}", link).expect("Failed to write to file");
                 }
+                for info in &tls_infos {
+                    let flag = if info.expired {
+                        " EXPIRED"
+                    } else if info.expiring_soon {
+                        " EXPIRING"
+                    } else {
+                        ""
+                    };
+                    writeln!(
+                        file,
+                        "[TLS] {} expires {} ({} days){}",
+                        info.host, info.expires, info.days_remaining, flag
+                    )
+                    .expect("Failed to write to file");
+                }
             }
             OutputFormat::Json(mut file) => {
                 let output = CrawlOutput {
                     root: &config.root_url,
-                    found_urls: visited.iter().map(|s| s.as_str()).collect(),
+                    found_urls: visited
+                        .iter()
+                        .filter(|u| !asset_urls.contains(*u))
+                        .map(|s| s.as_str())
+                        .collect(),
+                    assets: visited
+                        .iter()
+                        .filter(|u| asset_urls.contains(*u))
+                        .map(|s| s.as_str())
+                        .collect(),
+                    tls: tls_infos.iter().collect(),
                 };
                 let json = serde_json::to_string_pretty(&output).expect("Failed to serialize JSON");
                 file.write_all(json.as_bytes()).expect("Failed to write JSON to file");
@@ -105,9 +614,9 @@ async fn main() -> Result<(), reqwest::Error> {
         }
     }
 
-    // Add response error URLs if arg "-e" is set by user 
-    if config.response_error{
-        for item in error_links{
+    // Add response error URLs if arg "-e" is set by user
+    if config.response_error {
+        for item in error_links {
             visited.insert(item);
         }
     }
@@ -117,14 +626,14 @@ async fn main() -> Result<(), reqwest::Error> {
         for link in visited.iter() {
             log::info!("{:?}", link);
         }
-        
+
         println!("Crawling completed!");
     }
     else{
         println!("Crawling completed!");
     }
 
-    
+
     Ok(())
 }
 
@@ -138,6 +647,156 @@ async fn get_html(url: &str) -> Result<String, reqwest::Error> {
     Ok(html)
 }
 
+/// Performs a TLS handshake to `host:port`, reads the peer certificate's
+/// not-after date, and reports how many days remain, flagging certificates that
+/// are already expired or fall within `threshold` days of expiry.
+/// Returns `None` if the host can't be reached or the certificate can't be read.
+async fn probe_tls(host: &str, port: u16, threshold: i64) -> Option<TlsInfo> {
+    let host_owned = host.to_string();
+    let der = tokio::task::spawn_blocking(move || fetch_peer_cert(&host_owned, port))
+        .await
+        .ok()??;
+
+    let (_, cert) = x509_parser::parse_x509_certificate(&der).ok()?;
+    let not_after = cert.validity().not_after;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_secs() as i64;
+    let remaining_secs = not_after.timestamp() - now;
+    let days_remaining = remaining_secs / 86_400;
+
+    Some(TlsInfo {
+        host: host.to_string(),
+        expires: not_after.to_string(),
+        days_remaining,
+        expired: remaining_secs < 0,
+        expiring_soon: days_remaining <= threshold,
+    })
+}
+
+/// Connects to `host:port` and returns the peer's leaf certificate in DER form.
+/// Certificate validation is disabled so expired/invalid certs can still be
+/// inspected, which is the whole point of the audit. A connect/read timeout
+/// keeps an unresponsive host from stalling the worker indefinitely.
+fn fetch_peer_cert(host: &str, port: u16) -> Option<Vec<u8>> {
+    let connector = native_tls::TlsConnector::builder()
+        .danger_accept_invalid_certs(true)
+        .danger_accept_invalid_hostnames(true)
+        .build()
+        .ok()?;
+
+    let timeout = Duration::from_secs(10);
+    let addr = (host, port).to_socket_addrs().ok()?.next()?;
+    let stream = std::net::TcpStream::connect_timeout(&addr, timeout).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+    let tls = connector.connect(host, stream).ok()?;
+    let cert = tls.peer_certificate().ok()??;
+    cert.to_der().ok()
+}
+
+/// Fetches and parses `/robots.txt` for the root host, returning the rules for
+/// our user-agent together with any `Sitemap:` URLs it advertises.
+/// A missing or unreadable robots.txt is treated as "allow everything".
+async fn fetch_robots(root: &str) -> (RobotsRules, Vec<String>) {
+    let robots_url = match Url::parse(root).and_then(|u| u.join("/robots.txt")) {
+        Ok(url) => url.to_string(),
+        Err(_) => return (RobotsRules::default(), Vec::new()),
+    };
+
+    match get_html(&robots_url).await {
+        Ok(text) => parse_robots(&text),
+        Err(_) => (RobotsRules::default(), Vec::new()),
+    }
+}
+
+/// Parses robots.txt text, collecting the directives that apply to the `*`
+/// user-agent plus every `Sitemap:` line (which are global, not per agent).
+/// Example: parse_robots("User-agent: *\nDisallow: /private\n") -> rules with /private disallowed
+fn parse_robots(text: &str) -> (RobotsRules, Vec<String>) {
+    let mut rules = RobotsRules::default();
+    let mut sitemaps = Vec::new();
+    // Whether the group currently being read applies to our user-agent.
+    let mut applies = false;
+
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let (field, value) = match line.split_once(':') {
+            Some((f, v)) => (f.trim().to_lowercase(), v.trim().to_string()),
+            None => continue,
+        };
+
+        match field.as_str() {
+            "user-agent" => applies = value == "*",
+            "disallow" if applies && !value.is_empty() => rules.disallow.push(value),
+            "allow" if applies && !value.is_empty() => rules.allow.push(value),
+            "crawl-delay" if applies => rules.crawl_delay = value.parse().ok(),
+            "sitemap" if !value.is_empty() => sitemaps.push(value),
+            _ => (),
+        }
+    }
+
+    (rules, sitemaps)
+}
+
+/// Recursively harvests page URLs from a sitemap, following `<loc>` entries and
+/// descending into nested sitemaps when the document is a sitemap index.
+/// Unreachable or malformed sitemaps are skipped silently. The `seen` set
+/// guards against self- or mutually-referential sitemap indexes looping forever.
+fn collect_sitemap_urls<'a>(
+    url: &'a str,
+    out: &'a mut Vec<String>,
+    seen: &'a mut HashSet<String>,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + 'a>> {
+    Box::pin(async move {
+        if !seen.insert(url.to_string()) {
+            return; // Already fetched this sitemap
+        }
+
+        let text = match get_html(url).await {
+            Ok(text) => text,
+            Err(_) => return,
+        };
+
+        let locs = extract_tag(&text, "loc");
+        if text.contains("<sitemapindex") {
+            for loc in locs {
+                collect_sitemap_urls(&loc, out, seen).await;
+            }
+        } else {
+            for loc in locs {
+                if !out.contains(&loc) {
+                    out.push(loc);
+                }
+            }
+        }
+    })
+}
+
+/// Extracts the trimmed text content of every `<tag>...</tag>` pair in `xml`.
+/// Example: extract_tag("<loc>https://a/</loc>", "loc") -> ["https://a/"]
+fn extract_tag(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let mut out = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        match after.find(&close) {
+            Some(end) => {
+                out.push(after[..end].trim().to_string());
+                rest = &after[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    out
+}
+
 /// Checks if a candidate url has the same host then the root url.
 /// e.g., is_same_domain("https://example.com/", "https://example2.com/") -> false
 fn is_same_domain(root: &str, candidate: &str) -> bool {
@@ -145,7 +804,7 @@ fn is_same_domain(root: &str, candidate: &str) -> bool {
     let candidate_host = url::Url::parse(candidate).ok().and_then(|u| u.host_str().map(|h| h.to_string()));
 
     root_host == candidate_host
-} 
+}
 
 /// Checks the depth of the current url being listed has the same depth that the user wants to visit.
 /// e.g., depth_control("https://example.com/1/2/", 2) -> false
@@ -155,14 +814,14 @@ fn depth_control(url: &str, depth: i32) -> bool{
 
     let parsed_url = match url::Url::parse(url) {
         Ok(u) => u,
-        Err(_) => return false, 
+        Err(_) => return false,
     };
 
     // Count the segments
     let count = parsed_url
         .path_segments()
         .map(|segments| segments.filter(|s| !s.is_empty()).count() as i32)
-        .unwrap_or(-1); 
+        .unwrap_or(-1);
 
     if count == depth{
         return true;
@@ -191,24 +850,84 @@ fn get_url(root: &str, sub: &str) -> String {
     }
 }
 
-/// Extracts all anchor tag hrefs from HTML content and resolves them into absolute URLs.
-/// Example: get_links("<a href=\"/index.html\">Home</a>", "https://example.com", &mut links)
-fn get_links(html: &str, url: &str, results: &mut Vec<String>) {
-    let fragment = Html::parse_fragment(&html);
-    let selector = Selector::parse("a").unwrap();
+/// Extracts every linkable URL from HTML content, resolved to absolute form and
+/// classified as a navigable page or a static asset. Covers anchors, image
+/// maps, frames and forms as pages; stylesheets, scripts, images and `srcset`
+/// candidates as assets.
+/// Example: get_links("<a href=\"/index.html\">Home</a>", "https://example.com")
+///          -> [("https://example.com/index.html", LinkKind::Page)]
+fn get_links(html: &str, url: &str) -> Vec<(String, LinkKind)> {
+    let fragment = Html::parse_fragment(html);
+    let mut results: Vec<(String, LinkKind)> = Vec::new();
+
+    // (tag, attribute, classification) for plain single-URL attributes.
+    let rules = [
+        ("a", "href", LinkKind::Page),
+        ("area", "href", LinkKind::Page),
+        ("iframe", "src", LinkKind::Page),
+        ("form", "action", LinkKind::Page),
+        ("script", "src", LinkKind::Asset),
+        ("img", "src", LinkKind::Asset),
+    ];
+
+    for (tag, attr, kind) in rules {
+        let selector = Selector::parse(tag).unwrap();
+        for element in fragment.select(&selector) {
+            if let Some(val) = element.value().attr(attr) {
+                push_link(&mut results, url, val, kind);
+            }
+        }
+    }
+
+    // `<link href>` is an asset only for resource relations (stylesheet, icon,
+    // preload, manifest); rel="canonical"/"alternate"/"prev"/"next" point at
+    // navigable HTML pages, so classify by `rel`.
+    let selector = Selector::parse("link[href]").unwrap();
+    for element in fragment.select(&selector) {
+        if let Some(val) = element.value().attr("href") {
+            let kind = if link_rel_is_asset(element.value().attr("rel")) {
+                LinkKind::Asset
+            } else {
+                LinkKind::Page
+            };
+            push_link(&mut results, url, val, kind);
+        }
+    }
 
+    // `srcset` holds a comma-separated list of "<url> <descriptor>" candidates.
+    let selector = Selector::parse("img[srcset], source[srcset]").unwrap();
     for element in fragment.select(&selector) {
-        let text = element.value().attr("href");
-        match text {
-            Some(val) => {
-                let absolute = get_url(url, val);
-                if !results.contains(&absolute) {
-                    results.push(absolute);
+        if let Some(srcset) = element.value().attr("srcset") {
+            for candidate in srcset.split(',') {
+                if let Some(token) = candidate.split_whitespace().next() {
+                    push_link(&mut results, url, token, LinkKind::Asset);
                 }
-            },
-            None => (), // Skips anchor tags without href
+            }
         }
     }
+
+    results
+}
+
+/// Classifies a `<link rel>` value: resource relations (stylesheet, icon,
+/// preload, manifest) are static assets, while everything else (canonical,
+/// alternate, prev, next, …) links to a navigable page.
+fn link_rel_is_asset(rel: Option<&str>) -> bool {
+    match rel {
+        Some(rel) => rel.split_whitespace().any(|token| {
+            let token = token.to_ascii_lowercase();
+            token == "stylesheet" || token == "preload" || token == "manifest" || token.ends_with("icon")
+        }),
+        None => false,
+    }
+}
+
+/// Resolves `raw` against `base` and appends it (deduplicated) to `results`.
+fn push_link(results: &mut Vec<(String, LinkKind)>, base: &str, raw: &str, kind: LinkKind) {
+    let absolute = get_url(base, raw);
+    if !results.iter().any(|(existing, _)| existing == &absolute) {
+        results.push((absolute, kind));
+    }
 }
 
 /// Parses command-line arguments and returns the configuration for the crawler.
@@ -227,15 +946,23 @@ fn parse_args() -> Result<Config, io::Error> {
             \n\
             Options:\n\
             \t-d, --depth <n>              Limit the crawl depth (default: 0)\n\
+            \t-j, --jobs <n>               Number of concurrent workers (default: 8)\n\
             \t-f, --file [filename]        Write visited URLs to file (default: output.txt)\n\
             \t-fj, --file-json [filename]  Write visited URLs to JSON file (default: output.json)\n\
             \t-e, --request-error          Display/Save the URLs that have returned error in the request(default: disabled)\n\
+            \t--ignore-robots              Ignore robots.txt rules and skip sitemap seeding\n\
+            \t--rate <reqs_per_sec>        Limit requests per second per host (default: unlimited)\n\
+            \t--burst <n>                  Token bucket capacity per host (default: rate, at least 1)\n\
+            \t--assets                     Crawl and report static assets, not just navigational links\n\
+            \t--check-tls [days]           Audit HTTPS certificate expiry, flagging within [days] (default: 30)\n\
+            \t--state <file>               Checkpoint crawl state to <file> and resume from it if present\n\
             \t-v, --verbose                Enable verbose logging during the crawl.\n\
             \t-h, --help                   Display this help message and exit\n\
             \n\
             Examples:\n\
             \tweb_crawler https://example.com\n\
             \tweb_crawler https://example.com -d 2\n\
+            \tweb_crawler https://example.com -j 32\n\
             \tweb_crawler https://example.com -f\n\
             \tweb_crawler https://example.com -f results.txt -d 3\n\
             \tweb_crawler https://example.com -fj results.json\n\
@@ -250,8 +977,15 @@ fn parse_args() -> Result<Config, io::Error> {
 
     let root_url = args[1].clone();
     let mut depth: i32 = 0;
+    let mut jobs: usize = 8;
     let mut verbose: bool = false;
     let mut response_error: bool = false;
+    let mut ignore_robots: bool = false;
+    let mut rate: Option<f64> = None;
+    let mut burst: Option<f64> = None;
+    let mut assets: bool = false;
+    let mut check_tls: Option<i64> = None;
+    let mut state_file: Option<String> = None;
     let mut output_file: Option<OutputFormat> = None;
 
     let mut i = 2;
@@ -268,6 +1002,21 @@ fn parse_args() -> Result<Config, io::Error> {
                 });
                 i += 2;
             }
+            "-j" | "--jobs" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Expected value after {}", args[i]);
+                    std::process::exit(1);
+                }
+                jobs = args[i + 1].parse::<usize>().unwrap_or_else(|_| {
+                    eprintln!("Invalid jobs value");
+                    std::process::exit(1);
+                });
+                if jobs == 0 {
+                    eprintln!("Jobs value must be at least 1");
+                    std::process::exit(1);
+                }
+                i += 2;
+            }
             "-f" | "--f" => {
                 let filename = if i + 1 < args.len() && !args[i + 1].starts_with('-') {
                     i += 1;
@@ -296,6 +1045,69 @@ fn parse_args() -> Result<Config, io::Error> {
                 response_error = true;
                 i += 1;
             }
+            "--ignore-robots" => {
+                ignore_robots = true;
+                i += 1;
+            }
+            "--rate" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Expected value after {}", args[i]);
+                    std::process::exit(1);
+                }
+                let value = args[i + 1].parse::<f64>().unwrap_or_else(|_| {
+                    eprintln!("Invalid rate value");
+                    std::process::exit(1);
+                });
+                if !(value > 0.0) {
+                    eprintln!("Invalid rate value: must be greater than 0");
+                    std::process::exit(1);
+                }
+                rate = Some(value);
+                i += 2;
+            }
+            "--burst" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Expected value after {}", args[i]);
+                    std::process::exit(1);
+                }
+                let value = args[i + 1].parse::<f64>().unwrap_or_else(|_| {
+                    eprintln!("Invalid burst value");
+                    std::process::exit(1);
+                });
+                if !(value >= 1.0) {
+                    eprintln!("Invalid burst value: must be at least 1");
+                    std::process::exit(1);
+                }
+                burst = Some(value);
+                i += 2;
+            }
+            "--assets" => {
+                assets = true;
+                i += 1;
+            }
+            "--check-tls" => {
+                // Optional threshold in days; defaults to 30 when omitted.
+                let days = if i + 1 < args.len() && !args[i + 1].starts_with('-') {
+                    let parsed = args[i + 1].parse::<i64>().unwrap_or_else(|_| {
+                        eprintln!("Invalid check-tls value");
+                        std::process::exit(1);
+                    });
+                    i += 1;
+                    parsed
+                } else {
+                    30
+                };
+                check_tls = Some(days);
+                i += 1;
+            }
+            "--state" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Expected value after {}", args[i]);
+                    std::process::exit(1);
+                }
+                state_file = Some(args[i + 1].clone());
+                i += 2;
+            }
             _ => {
                 eprintln!("Unknown option: {}", args[i]);
                 std::process::exit(1);
@@ -306,8 +1118,15 @@ fn parse_args() -> Result<Config, io::Error> {
     Ok(Config {
         root_url,
         depth,
+        jobs,
         verbose,
         response_error,
+        ignore_robots,
+        rate,
+        burst,
+        assets,
+        check_tls,
+        state_file,
         output_file,
     })
 }